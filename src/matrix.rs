@@ -1,82 +1,55 @@
-//! Implements necessary methods on *square* matrices.
+//! Implements necessary methods on matrices.
 
 use std::{
     fmt,
     ops::{
         Add,
+        Div,
+        DivAssign,
         Index,
         IndexMut,
+        Mul,
+        MulAssign,
+        Neg,
         Sub,
     },
 };
 
-use super::Vector;
+use super::{ApproxEq, Float, Vector};
 
 #[derive(Clone, Copy, PartialEq, Debug)]
-/// Abstracts over a square matrix of arbitrary dimension.
-pub struct Matrix<const N: usize> {
+/// Abstracts over an `M`-by-`N` matrix of arbitrary dimension and element type, row-major.
+///
+/// `N` defaults to `M`, so `Matrix<N>` continues to mean a square matrix. `T` defaults to
+/// `f64`, so `Matrix<N>` and `Matrix<M, N>` continue to mean matrices of `f64`s.
+pub struct Matrix<const M: usize, const N: usize = M, T: Float = f64> {
     /// Contains the values of this matrix.
-    values: [[f64; N]; N],
+    values: [[T; N]; M],
 }
 
 /// Implements necessary behaviors of a matrix.
-impl<const N: usize> Matrix<N> {
+impl<const M: usize, const N: usize, T: Float> Matrix<M, N, T> {
     /// Constructs a zero matrix.
     pub fn zero() -> Self {
         Self {
-            values: [[0.0; N]; N],
-        }
-    }
-
-    /// Constructs an identity matrix.
-    pub fn identity() -> Self {
-        let mut values = [[0.0; N]; N];
-
-        for i in 0..N {
-            values[i][i] = 1.0;
-        }
-
-        Self {
-            values
+            values: [[T::zero(); N]; M],
         }
     }
 
     /// Constructs a matrix of provided values.
-    pub fn new(values: [[f64; N]; N]) -> Self {
+    pub fn new(values: [[T; N]; M]) -> Self {
         Self {
             values,
         }
     }
 
-    /// Returns a 3D rotation matrix representing a right-handed rotation about the
-    ///     provided axis by the provided angle.
-    /// 
-    /// *Note*: the provided angle is in radians.
-    pub fn rotation(
-        axis: Vector<3>,
-        angle: f64,
-    ) -> Matrix<3> {
-        let basis = Vector::<3>::basis();
-        let mut r = [Vector::<3>::zero(); 3];
-
-        for i in 0..3 {
-            r[i] = basis[i].rotate(axis, angle);
-        }
-
-        Matrix::<3>::new([
-            [r[0][0], r[1][0], r[2][0]],
-            [r[0][1], r[1][1], r[2][1]],
-            [r[0][2], r[1][2], r[2][2]],
-        ])
-    }
-
     /// Decomposes this matrix into its columns.
-    /// 
+    ///
     /// This is useful for determining the axes of a rotated coordinate system.
-    pub fn decompose(&self) -> [Vector<N>; N] {
+    pub fn decompose(&self) -> [Vector<M, T>; N] {
         let mut basis = [Vector::zero(); N];
 
-        for i in 0..N {
+        for i in 0..M {
             for j in 0..N {
                 basis[j][i] = self[(i, j)];
             }
@@ -86,31 +59,17 @@ impl<const N: usize> Matrix<N> {
     }
 
     /// Right-multiplies this matrix by the provided vector, returning the result.
-    pub fn mult(&self, vector: Vector<N>) -> Vector<N> {
-        let mut output = Vector::<N>::zero();
-
-        for i in 0..N {
-            for j in 0..N {
-                output[i] += self[(i, j)] * vector[j];
-            }
-        }
-
-        output
+    ///
+    /// This is a thin wrapper around the `Mul<Vector<N, T>>` operator.
+    pub fn mult(&self, vector: Vector<N, T>) -> Vector<M, T> {
+        *self * vector
     }
 
     /// Right-multiplies this matrix by the provided matrix, returning the result.
-    pub fn matmult(&self, matrix: Matrix<N>) -> Matrix<N> {
-        let mut output = Matrix::<N>::zero();
-
-        for i in 0..N {
-            for j in 0..N {
-                for k in 0..N {
-                    output[(i, j)] += self[(i, k)] * matrix[(k, j)];
-                }
-            }
-        }
-
-        output
+    ///
+    /// This is a thin wrapper around the `Mul<Matrix<N, K, T>>` operator.
+    pub fn matmult<const K: usize>(&self, matrix: Matrix<N, K, T>) -> Matrix<M, K, T> {
+        *self * matrix
     }
 
     /// Swap rows `i` and `j`.
@@ -121,17 +80,133 @@ impl<const N: usize> Matrix<N> {
     }
 
     /// Scale row `i` by factor `s`.
-    fn scalerow(&mut self, i: usize, s: f64) {
+    fn scalerow(&mut self, i: usize, s: T) {
         for j in 0..N {
             self[(i, j)] *= s;
         }
     }
 
     /// Subtract `s` times row `j` from row `i`.
-    fn subrow(&mut self, i: usize, j: usize, s: f64) {
+    fn subrow(&mut self, i: usize, j: usize, s: T) {
         for k in 0..N {
-            self[(i, k)] -= s * self[(j, k)];
+            let v = self[(j, k)];
+            self[(i, k)] -= s * v;
+        }
+    }
+
+    /// Scales a matrix by a provided scalar, returning the new matrix.
+    ///
+    /// This is a thin wrapper around the `Mul<T>` operator.
+    pub fn scale(&self, scalar: T) -> Self {
+        *self * scalar
+    }
+
+    /// Returns the transpose of this matrix.
+    pub fn transpose(&self) -> Matrix<N, M, T> {
+        let mut output = Matrix::<N, M, T>::zero();
+
+        for i in 0..M {
+            for j in 0..N {
+                output[(j, i)] = self[(i, j)];
+            }
+        }
+
+        output
+    }
+}
+
+/// Implements behaviors specific to square matrices.
+impl<const N: usize, T: Float> Matrix<N, N, T> {
+    /// Constructs an identity matrix.
+    pub fn identity() -> Self {
+        let mut values = [[T::zero(); N]; N];
+
+        for i in 0..N {
+            values[i][i] = T::one();
+        }
+
+        Self {
+            values
+        }
+    }
+
+    /// Returns the index of the row, at or below `start`, with the largest-magnitude
+    ///     entry in column `col`.
+    ///
+    /// Shared pivot-selection logic for `lu` and `inverse`.
+    fn pivot_row(&self, col: usize, start: usize) -> usize {
+        let mut best = start;
+        for k in (start + 1)..N {
+            if self[(k, col)].abs() > self[(best, col)].abs() {
+                best = k;
+            }
+        }
+
+        best
+    }
+
+    /// Factors this matrix as `P A = L U`, using partial pivoting on the largest-magnitude
+    ///     entry in each column.
+    ///
+    /// Returns the combined `L`/`U` matrix (`L` strictly below the diagonal with an implicit
+    ///     unit diagonal, `U` on and above it) along with the sign of the row-swap
+    ///     permutation `P` (`1` for an even number of swaps, `-1` for an odd number).
+    ///
+    /// If a column has no usable pivot (a singular matrix), the corresponding `U` diagonal
+    ///     entry is left at (approximately) zero rather than dividing by it, so that e.g.
+    ///     `determinant` collapses cleanly to zero instead of propagating `NaN`/`inf`.
+    fn lu(&self) -> (Self, T) {
+        let mut lu = *self;
+        let mut sign = T::one();
+
+        for i in 0..N {
+            let j = lu.pivot_row(i, i);
+
+            if j != i {
+                lu.swaprow(i, j);
+                sign = -sign;
+            }
+
+            if lu[(i, i)].abs() <= T::from_f64(1e-12) {
+                continue;
+            }
+
+            for k in (i + 1)..N {
+                let factor = lu[(k, i)] / lu[(i, i)];
+                lu[(k, i)] = factor;
+
+                for c in (i + 1)..N {
+                    let v = lu[(i, c)];
+                    lu[(k, c)] -= factor * v;
+                }
+            }
+        }
+
+        (lu, sign)
+    }
+
+    /// Returns the trace (the sum of the diagonal entries) of this matrix.
+    pub fn trace(&self) -> T {
+        let mut output = T::zero();
+
+        for i in 0..N {
+            output += self[(i, i)];
+        }
+
+        output
+    }
+
+    /// Returns the determinant of this matrix, computed from an LU factorization with
+    ///     partial pivoting.
+    pub fn determinant(&self) -> T {
+        let (lu, sign) = self.lu();
+
+        let mut det = sign;
+        for i in 0..N {
+            det *= lu[(i, i)];
         }
+
+        det
     }
 
     /// Returns the inverse of this matrix.
@@ -140,21 +215,16 @@ impl<const N: usize> Matrix<N> {
         let mut inverse = Self::identity();
 
         for i in 0..N {
-            // Determine the index of the row with the largest pivot
-            // Start from the working row
-            let mut j = i;
-            for k in i..N {
-                if output[(k, i)] > output[(i, i)] {
-                    j = k;
-                }
-            }
+            // Determine the index of the row with the largest-magnitude pivot,
+            // using the same pivot search as `lu`.
+            let j = output.pivot_row(i, i);
 
             // Swap largest pivot to working row
             output.swaprow(i, j);
             inverse.swaprow(i, j);
 
             // Normalize this row
-            let s = 1.0 / output[(i, i)];
+            let s = T::one() / output[(i, i)];
             output.scalerow(i, s);
             inverse.scalerow(i, s);
 
@@ -179,44 +249,186 @@ impl<const N: usize> Matrix<N> {
         inverse
     }
 
-    /// Scales a matrix by a provided scalar, returning the new matrix.
-    pub fn scale(&self, scalar: f64) -> Self {
-        let mut newvalues = [[0.0; N]; N];
+    /// Computes the QR decomposition of this matrix using modified Gram–Schmidt.
+    ///
+    /// Returns `(Q, R)` such that `Q` has orthonormal columns, `R` is upper triangular,
+    ///     and `self == Q.matmult(R)`.
+    pub fn qr(&self) -> (Self, Self) {
+        let v = self.decompose();
+        let mut q = [Vector::<N, T>::zero(); N];
+        let mut r = Self::zero();
+
+        for i in 0..N {
+            let mut u = v[i];
+
+            for j in 0..i {
+                r[(j, i)] = q[j].dot(u);
+                u = u - q[j].scale(r[(j, i)]);
+            }
+
+            r[(i, i)] = u.norm();
+            q[i] = u.normalize();
+        }
+
+        let mut qmat = Self::zero();
         for i in 0..N {
             for j in 0..N {
-                newvalues[i][j] = scalar * self[(i, j)];
+                qmat[(i, j)] = q[j][i];
             }
         }
 
-        Self {
-            values: newvalues,
+        (qmat, r)
+    }
+
+    /// Solves `self * x = b` for `x` via the QR decomposition, a numerically better
+    ///     alternative to forming the full inverse.
+    pub fn solve(&self, b: Vector<N, T>) -> Vector<N, T> {
+        let (q, r) = self.qr();
+        let y = q.transpose().mult(b);
+
+        let mut x = Vector::<N, T>::zero();
+        for i in (0..N).rev() {
+            let mut sum = y[i];
+            for j in (i + 1)..N {
+                sum -= r[(i, j)] * x[j];
+            }
+            x[i] = sum / r[(i, i)];
         }
+
+        x
+    }
+
+    /// Computes the eigenvalues and an orthonormal eigenvector matrix of this symmetric
+    ///     matrix, using the classical Jacobi rotation method (each rotation zeros the
+    ///     off-diagonal entry of largest magnitude).
+    ///
+    /// The `i`th returned eigenvalue corresponds to the `i`th column of the returned
+    ///     eigenvector matrix. Behavior is unspecified if `self` is not symmetric.
+    pub fn eigen_symmetric(&self) -> ([T; N], Self) {
+        let mut a = *self;
+        let mut v = Self::identity();
+
+        let mut norm = T::zero();
+        for i in 0..N {
+            for j in 0..N {
+                norm += a[(i, j)] * a[(i, j)];
+            }
+        }
+        let tolerance = T::from_f64(1e-12) * norm.sqrt();
+
+        let max_iterations = 100 * N * N;
+        for _ in 0..max_iterations {
+            // Find the off-diagonal entry of largest magnitude.
+            let mut p = 0;
+            let mut q = 1.min(N - 1);
+            let mut largest = T::zero();
+            for i in 0..N {
+                for j in (i + 1)..N {
+                    if a[(i, j)].abs() > largest {
+                        largest = a[(i, j)].abs();
+                        p = i;
+                        q = j;
+                    }
+                }
+            }
+
+            if largest <= tolerance {
+                break;
+            }
+
+            // Compute the Jacobi rotation that zeros `a[(p, q)]`.
+            let theta = (a[(q, q)] - a[(p, p)]) / (T::from_f64(2.0) * a[(p, q)]);
+            let sign = if theta >= T::zero() { T::one() } else { -T::one() };
+            let t = sign / (theta.abs() + (theta * theta + T::one()).sqrt());
+            let c = T::one() / (t * t + T::one()).sqrt();
+            let s = t * c;
+
+            // Apply the rotation `A <- J^T A J`, updating only rows/columns `p` and `q`.
+            let app = a[(p, p)];
+            let aqq = a[(q, q)];
+            let apq = a[(p, q)];
+
+            a[(p, p)] = app - t * apq;
+            a[(q, q)] = aqq + t * apq;
+            a[(p, q)] = T::zero();
+            a[(q, p)] = T::zero();
+
+            for i in 0..N {
+                if i != p && i != q {
+                    let aip = a[(i, p)];
+                    let aiq = a[(i, q)];
+                    a[(i, p)] = c * aip - s * aiq;
+                    a[(p, i)] = a[(i, p)];
+                    a[(i, q)] = s * aip + c * aiq;
+                    a[(q, i)] = a[(i, q)];
+                }
+            }
+
+            // Accumulate the rotation into the eigenvector matrix: `V <- V J`.
+            for i in 0..N {
+                let vip = v[(i, p)];
+                let viq = v[(i, q)];
+                v[(i, p)] = c * vip - s * viq;
+                v[(i, q)] = s * vip + c * viq;
+            }
+        }
+
+        let mut eigenvalues = [T::zero(); N];
+        for i in 0..N {
+            eigenvalues[i] = a[(i, i)];
+        }
+
+        (eigenvalues, v)
+    }
+}
+
+impl<T: Float> Matrix<3, 3, T> {
+    /// Returns a 3D rotation matrix representing a right-handed rotation about the
+    ///     provided axis by the provided angle.
+    ///
+    /// *Note*: the provided angle is in radians.
+    pub fn rotation(
+        axis: Vector<3, T>,
+        angle: T,
+    ) -> Matrix<3, 3, T> {
+        let basis = Vector::<3, T>::basis();
+        let mut r = [Vector::<3, T>::zero(); 3];
+
+        for i in 0..3 {
+            r[i] = basis[i].rotate(axis, angle);
+        }
+
+        Matrix::<3, 3, T>::new([
+            [r[0][0], r[1][0], r[2][0]],
+            [r[0][1], r[1][1], r[2][1]],
+            [r[0][2], r[1][2], r[2][2]],
+        ])
     }
 }
 
-impl<const N: usize> Index<(usize, usize)> for Matrix<N> {
-    type Output = f64;
+impl<const M: usize, const N: usize, T: Float> Index<(usize, usize)> for Matrix<M, N, T> {
+    type Output = T;
 
     fn index(&self, idx: (usize, usize)) -> &Self::Output {
         &self.values[idx.0][idx.1]
     }
 }
 
-impl<const N: usize> IndexMut<(usize, usize)> for Matrix<N> {
+impl<const M: usize, const N: usize, T: Float> IndexMut<(usize, usize)> for Matrix<M, N, T> {
     fn index_mut(&mut self, idx: (usize, usize)) -> &mut Self::Output {
         &mut self.values[idx.0][idx.1]
     }
 }
 
-impl<const N: usize> Add for Matrix<N> {
+impl<const M: usize, const N: usize, T: Float> Add for Matrix<M, N, T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         let mut new = Self::zero();
-    
-        for i in 0..N {
+
+        for i in 0..M {
             for j in 0..N {
-                new[(i, j)] = self[(i, j)] + other[(i, j)];   
+                new[(i, j)] = self[(i, j)] + other[(i, j)];
             }
         }
 
@@ -224,15 +436,15 @@ impl<const N: usize> Add for Matrix<N> {
     }
 }
 
-impl<const N: usize> Sub for Matrix<N> {
+impl<const M: usize, const N: usize, T: Float> Sub for Matrix<M, N, T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         let mut new = Self::zero();
-    
-        for i in 0..N {
+
+        for i in 0..M {
             for j in 0..N {
-                new[(i, j)] = self[(i, j)] - other[(i, j)];   
+                new[(i, j)] = self[(i, j)] - other[(i, j)];
             }
         }
 
@@ -240,13 +452,157 @@ impl<const N: usize> Sub for Matrix<N> {
     }
 }
 
-impl<const N: usize> fmt::Display for Matrix<N> {
+impl<const M: usize, const N: usize, const K: usize, T: Float> Mul<Matrix<N, K, T>> for Matrix<M, N, T> {
+    type Output = Matrix<M, K, T>;
+
+    fn mul(self, matrix: Matrix<N, K, T>) -> Matrix<M, K, T> {
+        let mut output = Matrix::<M, K, T>::zero();
+
+        for i in 0..M {
+            for j in 0..K {
+                for k in 0..N {
+                    output[(i, j)] += self[(i, k)] * matrix[(k, j)];
+                }
+            }
+        }
+
+        output
+    }
+}
+
+impl<const M: usize, const N: usize, T: Float> Mul<Vector<N, T>> for Matrix<M, N, T> {
+    type Output = Vector<M, T>;
+
+    fn mul(self, vector: Vector<N, T>) -> Vector<M, T> {
+        let mut output = Vector::<M, T>::zero();
+
+        for i in 0..M {
+            for j in 0..N {
+                output[i] += self[(i, j)] * vector[j];
+            }
+        }
+
+        output
+    }
+}
+
+impl<const M: usize, const N: usize, T: Float> Mul<T> for Matrix<M, N, T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        let mut new = Self::zero();
+
+        for i in 0..M {
+            for j in 0..N {
+                new[(i, j)] = scalar * self[(i, j)];
+            }
+        }
+
+        new
+    }
+}
+
+impl<const M: usize, const N: usize, T: Float> Div<T> for Matrix<M, N, T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        let mut new = Self::zero();
+
+        for i in 0..M {
+            for j in 0..N {
+                new[(i, j)] = self[(i, j)] / scalar;
+            }
+        }
+
+        new
+    }
+}
+
+impl<const M: usize, const N: usize, T: Float> MulAssign<T> for Matrix<M, N, T> {
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+
+impl<const M: usize, const N: usize, T: Float> DivAssign<T> for Matrix<M, N, T> {
+    fn div_assign(&mut self, scalar: T) {
+        *self = *self / scalar;
+    }
+}
+
+impl<const M: usize, const N: usize, T: Float> Neg for Matrix<M, N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let mut new = Self::zero();
+
+        for i in 0..M {
+            for j in 0..N {
+                new[(i, j)] = -self[(i, j)];
+            }
+        }
+
+        new
+    }
+}
+
+macro_rules! impl_scalar_mul_matrix {
+    ($t:ty) => {
+        impl<const M: usize, const N: usize> Mul<Matrix<M, N, $t>> for $t {
+            type Output = Matrix<M, N, $t>;
+
+            fn mul(self, matrix: Matrix<M, N, $t>) -> Matrix<M, N, $t> {
+                matrix * self
+            }
+        }
+    };
+}
+
+impl_scalar_mul_matrix!(f64);
+impl_scalar_mul_matrix!(f32);
+
+impl<const M: usize, const N: usize, T: Float> ApproxEq for Matrix<M, N, T> {
+    type Epsilon = T;
+
+    fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        for i in 0..M {
+            for j in 0..N {
+                if (self[(i, j)] - other[(i, j)]).abs() > epsilon {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T) -> bool {
+        for i in 0..M {
+            for j in 0..N {
+                let largest = if self[(i, j)].abs() > other[(i, j)].abs() {
+                    self[(i, j)].abs()
+                } else {
+                    other[(i, j)].abs()
+                };
+                let bound = if largest > T::one() { epsilon * largest } else { epsilon };
+
+                if (self[(i, j)] - other[(i, j)]).abs() > bound {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl<const M: usize, const N: usize, T: Float> fmt::Display for Matrix<M, N, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut values = Vec::new();
         let mut maxlen = 0;
-        for i in 0..N {
+        for i in 0..M {
             for j in 0..N {
-                let value = self[(i, j)];
+                let value = self[(i, j)].to_f64();
                 let row = if value >= 0.0 {
                     format!(" {:.8}", value)
                 } else {
@@ -261,7 +617,7 @@ impl<const N: usize> fmt::Display for Matrix<N> {
         }
 
         let mut output = String::new();
-        for i in 0..N {
+        for i in 0..M {
             output.push_str("[");
             for j in 0..N {
                 output.push_str(
@@ -297,7 +653,41 @@ fn matrix_multiply() {
 
     println!("{}", c);
 
-    assert_eq!(a.matmult(b), c);
+    assert!(a.matmult(b).approx_eq(&c, 1e-9));
+}
+
+#[test]
+fn matrix_multiply_rectangular() {
+    let a: Matrix<2, 3> = Matrix::new([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+    ]);
+
+    let b: Matrix<3, 2> = Matrix::new([
+        [7.0, 8.0],
+        [9.0, 10.0],
+        [11.0, 12.0],
+    ]);
+
+    let c: Matrix<2, 2> = Matrix::new([
+        [58.0, 64.0],
+        [139.0, 154.0],
+    ]);
+
+    assert!(a.matmult(b).approx_eq(&c, 1e-9));
+}
+
+#[test]
+fn matrix_multiply_f32() {
+    let a: Matrix<3, 3, f32> = Matrix::new([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+        [7.0, 8.0, 9.0],
+    ]);
+
+    let b: Matrix<3, 3, f32> = Matrix::identity();
+
+    assert!(a.matmult(b).approx_eq(&a, 1e-5));
 }
 
 #[test]
@@ -331,4 +721,133 @@ fn decomposition() {
     let basis = Vector::<3>::basis();
 
     println!("{:#?}", basis);
-}
\ No newline at end of file
+}
+
+#[test]
+fn transpose() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+    ]);
+
+    let b = Matrix::new([
+        [1.0, 4.0],
+        [2.0, 5.0],
+        [3.0, 6.0],
+    ]);
+
+    assert_eq!(a.transpose(), b);
+}
+
+#[test]
+fn trace() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+        [7.0, 8.0, 9.0],
+    ]);
+
+    assert_eq!(a.trace(), 15.0);
+}
+
+#[test]
+fn determinant() {
+    let a = Matrix::new([
+        [2.0, -1.0, 0.0],
+        [-1.0, 2.0, -1.0],
+        [0.0, -1.0, 2.0],
+    ]);
+
+    assert!((a.determinant() - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn determinant_singular() {
+    let a = Matrix::new([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+        [0.0, 0.0, 0.0],
+    ]);
+
+    assert!(a.determinant().abs() < 1e-9);
+}
+
+#[test]
+fn qr_reconstructs_matrix() {
+    let a = Matrix::new([
+        [12.0, -51.0, 4.0],
+        [6.0, 167.0, -68.0],
+        [-4.0, 24.0, -41.0],
+    ]);
+
+    let (q, r) = a.qr();
+
+    let reconstructed = q.matmult(r);
+    assert!(reconstructed.approx_eq(&a, 1e-9));
+}
+
+#[test]
+fn solve_linear_system() {
+    let a = Matrix::new([
+        [2.0, -1.0, 0.0],
+        [-1.0, 2.0, -1.0],
+        [0.0, -1.0, 2.0],
+    ]);
+
+    let b: Vector<3> = [1.0, 0.0, 1.0].into();
+    let x = a.solve(b);
+
+    let reconstructed = a.mult(x);
+    assert!(reconstructed.approx_eq(&b, 1e-9));
+}
+
+#[test]
+fn eigen_symmetric() {
+    let a = Matrix::new([
+        [2.0, 1.0],
+        [1.0, 2.0],
+    ]);
+
+    let (eigenvalues, eigenvectors) = a.eigen_symmetric();
+
+    // Eigenvalues of [[2, 1], [1, 2]] are 1 and 3, in some order.
+    let mut sorted = eigenvalues;
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((sorted[0] - 1.0).abs() < 1e-9);
+    assert!((sorted[1] - 3.0).abs() < 1e-9);
+
+    // `A v = lambda v` should hold for each eigenpair.
+    let columns = eigenvectors.decompose();
+    for i in 0..2 {
+        let av = a.mult(columns[i]);
+        let lambda_v = columns[i].scale(eigenvalues[i]);
+        assert!(av.approx_eq(&lambda_v, 1e-9));
+    }
+}
+
+#[test]
+fn approx_eq() {
+    let a = Matrix::new([
+        [1.0, 2.0],
+        [3.0, 4.0],
+    ]);
+    let b = Matrix::new([
+        [1.0 + 1e-10, 2.0],
+        [3.0, 4.0 - 1e-10],
+    ]);
+
+    assert!(a.approx_eq(&b, 1e-9));
+    assert!(!a.approx_eq(&b, 1e-11));
+    assert!(a.relative_eq(&b, 1e-9));
+}
+
+#[test]
+fn operator_overloads() {
+    let a = Matrix::<3>::identity();
+    let v: Vector<3> = [1.0, 2.0, 3.0].into();
+
+    assert_eq!(a * v, v);
+    assert_eq!(2.0 * a * v, v * 2.0);
+    assert_eq!((a * 2.0) / 2.0, a);
+    assert_eq!(-(-a), a);
+}
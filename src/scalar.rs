@@ -0,0 +1,126 @@
+//! Defines the numeric trait that generalizes `Vector` and `Matrix` over their element type.
+
+use std::{
+    fmt,
+    ops::{
+        Add,
+        AddAssign,
+        Div,
+        DivAssign,
+        Mul,
+        MulAssign,
+        Neg,
+        Sub,
+        SubAssign,
+    },
+};
+
+/// A scalar type usable as the element type of a `Vector` or `Matrix`.
+///
+/// Implemented here for `f64` and `f32`. Downstream crates may implement this for their
+/// own numeric types (fixed-point, complex, etc.) to use them with this crate's vectors
+/// and matrices.
+pub trait Float:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Returns the square root of this value.
+    fn sqrt(self) -> Self;
+
+    /// Returns the sine of this value, in radians.
+    fn sin(self) -> Self;
+
+    /// Returns the cosine of this value, in radians.
+    fn cos(self) -> Self;
+
+    /// Raises this value to the power `n`.
+    fn powf(self, n: Self) -> Self;
+
+    /// Returns the absolute value of this value.
+    fn abs(self) -> Self;
+
+    /// Converts this value to an `f64`, e.g. for display or sampling.
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` into this type, e.g. for constants or sampling.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ty) => {
+        impl Float for $t {
+            fn zero() -> Self {
+                0.0
+            }
+
+            fn one() -> Self {
+                1.0
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                <$t>::powf(self, n)
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+        }
+    };
+}
+
+impl_float!(f64);
+impl_float!(f32);
+
+/// Types that support approximate equality comparisons, for validating results (like
+/// rotations, inverses, and decompositions) that are correct only up to floating-point
+/// rounding.
+pub trait ApproxEq {
+    /// The scalar type used to express tolerances.
+    type Epsilon;
+
+    /// Returns `true` if `self` and `other` are within `epsilon` of each other,
+    ///     element-wise.
+    fn approx_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    /// Returns `true` if `self` and `other` are within `epsilon` of each other relative
+    ///     to their magnitude, element-wise. Where both magnitudes are at most one,
+    ///     `epsilon` is used as an absolute tolerance.
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+}
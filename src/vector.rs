@@ -4,15 +4,21 @@ use std::{
     fmt,
     ops::{
         Add,
-        Sub,
+        Div,
+        DivAssign,
         Index,
         IndexMut,
+        Mul,
+        MulAssign,
+        Neg,
+        Sub,
     },
 };
 
 use rand::{
     random,
     thread_rng,
+    distributions::uniform::SampleUniform,
     prelude::SliceRandom,
 };
 
@@ -21,26 +27,28 @@ use rand_distr::{
     Normal,
 };
 
-use super::Matrix;
+use super::{ApproxEq, Float, Matrix};
 
 #[derive(Clone, Copy, PartialEq, Debug)]
-/// Abstracts over a vector of arbitary dimension.
-pub struct Vector<const N: usize> {
+/// Abstracts over a vector of arbitary dimension and element type.
+///
+/// `T` defaults to `f64`, so `Vector<N>` continues to mean a vector of `f64`s.
+pub struct Vector<const N: usize, T: Float = f64> {
     /// Contains the values of this vector.
-    pub values: [f64; N],
+    pub values: [T; N],
 }
 
 /// Implements necessary behaviors of a vector.
-impl<const N: usize> Vector<N> {
+impl<const N: usize, T: Float> Vector<N, T> {
     /// Constructs a zero vector.
     pub fn zero() -> Self {
         Self {
-            values: [0.0; N],
+            values: [T::zero(); N],
         }
     }
 
     /// Constructs a vector of provided values.
-    pub fn new(values: [f64; N]) -> Self {
+    pub fn new(values: [T; N]) -> Self {
         Self {
             values,
         }
@@ -54,20 +62,15 @@ impl<const N: usize> Vector<N> {
     }
 
     /// Scales a vector by a provided scalar, returning the new vector.
-    pub fn scale(&self, scalar: f64) -> Self {
-        let mut newvalues = [0.0; N];
-        for i in 0..N {
-            newvalues[i] = scalar * self[i];
-        }
-
-        Self {
-            values: newvalues,
-        }
+    ///
+    /// This is a thin wrapper around the `Mul<T>` operator.
+    pub fn scale(&self, scalar: T) -> Self {
+        *self * scalar
     }
-    
+
     /// Dots this vector with another vector.
-    pub fn dot(&self, other: Self) -> f64 {
-        let mut output = 0.0;
+    pub fn dot(&self, other: Self) -> T {
+        let mut output = T::zero();
 
         for i in 0..N {
             output += self[i] * other[i];
@@ -77,11 +80,11 @@ impl<const N: usize> Vector<N> {
     }
 
     /// Takes the norm of a vector.
-    pub fn norm(&self) -> f64 {
-        let mut output = 0.0;
+    pub fn norm(&self) -> T {
+        let mut output = T::zero();
 
         for i in 0..N {
-            output += self[i].powf(2.0);
+            output += self[i].powf(T::from_f64(2.0));
         }
 
         output.sqrt()
@@ -89,11 +92,11 @@ impl<const N: usize> Vector<N> {
 
     /// Returns the unit vector parallel to this vector.
     pub fn normalize(&self) -> Self {
-        self.scale(1.0 / self.norm())
+        self.scale(T::one() / self.norm())
     }
 
     /// Left-multiplies the provided matrix by the transpose of this vector, returning the result.
-    pub fn mult(&self, matrix: Matrix<N>) -> Self {
+    pub fn mult(&self, matrix: Matrix<N, N, T>) -> Self {
         let mut output = Self::zero();
 
         for i in 0..N {
@@ -107,7 +110,10 @@ impl<const N: usize> Vector<N> {
 
     /// Given two vectors, generate a "child" vector.
     /// This function is useful for genetic optimization algorithms.
-    pub fn child(mother: &Self, father: &Self, stdev: f64) -> Self {
+    pub fn child(mother: &Self, father: &Self, stdev: T) -> Self
+    where
+        T: SampleUniform,
+    {
         let mut child = Self::zero();
 
         for i in 0..N {
@@ -122,9 +128,9 @@ impl<const N: usize> Vector<N> {
             // NOTE: it's ok to use `unwrap` here because we
             // know that we will always be able to create a normal
             // distribution of type N(0, `stdev`)
-            let normal = Normal::new(0.0, stdev).unwrap();
+            let normal = Normal::new(0.0, stdev.to_f64()).unwrap();
             let v = normal.sample(&mut thread_rng());
-            child[i] += v;
+            child[i] += T::from_f64(v);
         }
 
         child
@@ -132,7 +138,10 @@ impl<const N: usize> Vector<N> {
 
     /// Given two discrete vectors, generate a "child" discrete vector.
     /// This function is useful for *discrete* genetic optimization algorithms.
-    pub fn child_discrete(mother: &Self, father: &Self, permitted: &[f64]) -> Self {
+    pub fn child_discrete(mother: &Self, father: &Self, permitted: &[T]) -> Self
+    where
+        T: SampleUniform,
+    {
         let mut child = Self::zero();
 
         for i in 0..N {
@@ -154,14 +163,14 @@ impl<const N: usize> Vector<N> {
     }
 
     /// Determines if this vector is within the element-wise contraints.
-    pub fn check(&self, lower: [Option<f64>; N], upper: [Option<f64>; N]) -> bool {
+    pub fn check(&self, lower: [Option<T>; N], upper: [Option<T>; N]) -> bool {
         for i in 0..N {
             if let Some (l) = lower[i] {
                 if self[i] < l {
                     return false;
                 }
             }
-            
+
             if let Some (u) = upper[i] {
                 if self[i] > u {
                     return false;
@@ -173,16 +182,16 @@ impl<const N: usize> Vector<N> {
     }
 }
 
-impl Vector<3> {
+impl<T: Float> Vector<3, T> {
     /// Rotates this vector by the provided axis by the provided angle
     ///     using Rodrigues' rotation formula.
-    /// 
+    ///
     /// *Note*: the provided angle is in radians.
-    pub fn rotate(&self, axis: Vector<3>, angle: f64) -> Self {
+    pub fn rotate(&self, axis: Vector<3, T>, angle: T) -> Self {
         let k = axis.normalize();
 
         self.scale(angle.cos())
-            + k.scale(self.dot(k) * (1.0 - angle.cos()))
+            + k.scale(self.dot(k) * (T::one() - angle.cos()))
             + k.cross(*self).scale(angle.sin())
     }
 
@@ -196,38 +205,38 @@ impl Vector<3> {
     }
 }
 
-impl<const N: usize> From<[f64; N]> for Vector<N> {
-    fn from(values: [f64; N]) -> Self {
+impl<const N: usize, T: Float> From<[T; N]> for Vector<N, T> {
+    fn from(values: [T; N]) -> Self {
         Self::new(values)
     }
 }
 
-impl<const N: usize> From<Vector<N>> for [f64; N] {
-    fn from(vector: Vector<N>) -> Self {
+impl<const N: usize, T: Float> From<Vector<N, T>> for [T; N] {
+    fn from(vector: Vector<N, T>) -> Self {
         vector.values
     }
 }
 
-impl<const N: usize> Index<usize> for Vector<N> {
-    type Output = f64;
+impl<const N: usize, T: Float> Index<usize> for Vector<N, T> {
+    type Output = T;
 
     fn index(&self, idx: usize) -> &Self::Output {
         &self.values[idx]
     }
 }
 
-impl<const N: usize> IndexMut<usize> for Vector<N> {
+impl<const N: usize, T: Float> IndexMut<usize> for Vector<N, T> {
     fn index_mut(&mut self, idx: usize) -> &mut Self::Output {
         &mut self.values[idx]
     }
 }
 
-impl<const N: usize> Add for Vector<N> {
+impl<const N: usize, T: Float> Add for Vector<N, T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
         let mut new = Self::zero();
-        
+
         for i in 0..self.values.len() {
             new[i] = self[i] + other[i];
         }
@@ -236,12 +245,12 @@ impl<const N: usize> Add for Vector<N> {
     }
 }
 
-impl<const N: usize> Sub for Vector<N> {
+impl<const N: usize, T: Float> Sub for Vector<N, T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
         let mut new = Self::zero();
-        
+
         for i in 0..N {
             new[i] = self[i] - other[i];
         }
@@ -250,12 +259,112 @@ impl<const N: usize> Sub for Vector<N> {
     }
 }
 
-impl<const N: usize> fmt::Display for Vector<N> {
+impl<const N: usize, T: Float> Mul<T> for Vector<N, T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        let mut new = Self::zero();
+
+        for i in 0..N {
+            new[i] = scalar * self[i];
+        }
+
+        new
+    }
+}
+
+impl<const N: usize, T: Float> Div<T> for Vector<N, T> {
+    type Output = Self;
+
+    fn div(self, scalar: T) -> Self {
+        let mut new = Self::zero();
+
+        for i in 0..N {
+            new[i] = self[i] / scalar;
+        }
+
+        new
+    }
+}
+
+impl<const N: usize, T: Float> MulAssign<T> for Vector<N, T> {
+    fn mul_assign(&mut self, scalar: T) {
+        *self = *self * scalar;
+    }
+}
+
+impl<const N: usize, T: Float> DivAssign<T> for Vector<N, T> {
+    fn div_assign(&mut self, scalar: T) {
+        *self = *self / scalar;
+    }
+}
+
+impl<const N: usize, T: Float> Neg for Vector<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let mut new = Self::zero();
+
+        for i in 0..N {
+            new[i] = -self[i];
+        }
+
+        new
+    }
+}
+
+macro_rules! impl_scalar_mul_vector {
+    ($t:ty) => {
+        impl<const N: usize> Mul<Vector<N, $t>> for $t {
+            type Output = Vector<N, $t>;
+
+            fn mul(self, vector: Vector<N, $t>) -> Vector<N, $t> {
+                vector * self
+            }
+        }
+    };
+}
+
+impl_scalar_mul_vector!(f64);
+impl_scalar_mul_vector!(f32);
+
+impl<const N: usize, T: Float> ApproxEq for Vector<N, T> {
+    type Epsilon = T;
+
+    fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        for i in 0..N {
+            if (self[i] - other[i]).abs() > epsilon {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T) -> bool {
+        for i in 0..N {
+            let largest = if self[i].abs() > other[i].abs() {
+                self[i].abs()
+            } else {
+                other[i].abs()
+            };
+            let bound = if largest > T::one() { epsilon * largest } else { epsilon };
+
+            if (self[i] - other[i]).abs() > bound {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<const N: usize, T: Float> fmt::Display for Vector<N, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut rows = Vec::new();
         let mut maxlen = 0;
         for i in 0..N {
-            let value = self[i];
+            let value = self[i].to_f64();
             let row = if value >= 0.0 {
                 format!(" {:.8}", value)
             } else {
@@ -285,4 +394,23 @@ impl<const N: usize> fmt::Display for Vector<N> {
 fn display_vector() {
     let vec: Vector<3> = [-0.15, 10.0, 1000.0].into();
     println!("{}", vec);
-}
\ No newline at end of file
+}
+
+#[test]
+fn approx_eq() {
+    let a: Vector<3> = [1.0, 2.0, 3.0].into();
+    let b: Vector<3> = [1.0 + 1e-10, 2.0 - 1e-10, 3.0].into();
+
+    assert!(a.approx_eq(&b, 1e-9));
+    assert!(!a.approx_eq(&b, 1e-11));
+    assert!(a.relative_eq(&b, 1e-9));
+}
+
+#[test]
+fn operator_overloads() {
+    let v: Vector<3> = [1.0, 2.0, 3.0].into();
+
+    assert_eq!(v * 2.0, 2.0 * v);
+    assert_eq!((v * 2.0) / 2.0, v);
+    assert_eq!(-(-v), v);
+}